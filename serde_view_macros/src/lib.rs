@@ -3,27 +3,47 @@ extern crate proc_macro;
 use convert_case::{Case, Casing};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields};
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Fields, FieldsNamed, GenericArgument,
+    PathArguments, Type,
+};
 
-#[proc_macro_derive(View)]
+#[proc_macro_derive(View, attributes(view))]
 pub fn view(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let DeriveInput {
         ident,
         data,
         generics,
+        attrs,
         ..
     } = parse_macro_input!(input as DeriveInput);
 
-    let data = match data {
-        Data::Struct(data) => data,
-        _ => panic!("Derive can only be used on struct types"),
-    };
-
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let fields_name = format_ident!("{}Fields", ident);
 
-    let expanded_fields_name = view_fields(&fields_name, &data);
+    let field_entries = match &data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => named_field_entries(fields, rename_all_case(&attrs)),
+            Fields::Unnamed(fields) => tuple_field_entries(fields),
+            Fields::Unit => Vec::new(),
+        },
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .filter_map(|variant| match &variant.fields {
+                Fields::Named(fields) => {
+                    Some(named_field_entries(fields, rename_all_case(&variant.attrs)))
+                }
+                Fields::Unnamed(_) | Fields::Unit => None,
+            })
+            .flatten()
+            .collect(),
+        Data::Union(_) => panic!("Derive cannot be used on union types"),
+    };
+    let field_entries = dedup_field_entries(field_entries);
+
+    let expanded_fields_name = view_fields(&fields_name, field_entries);
 
     let expanded = quote! {
         impl #impl_generics View for #ident #ty_generics #where_clause {
@@ -44,42 +64,202 @@ pub fn view(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     proc_macro::TokenStream::from(expanded)
 }
 
-fn view_fields(name: &Ident, data: &DataStruct) -> TokenStream {
-    let fields = match &data.fields {
-        Fields::Named(fields) => fields,
-        _ => {
-            panic!("Derive can only be used on a struct with named fields");
-        }
-    };
+/// A single projected field: its serialized name, the `Fields` variant identifier that
+/// represents it, the `#[view(group(s) = ...)]` groups it belongs to, and, if it is
+/// `#[view(nested)]`, the (unwrapped) Rust type of the nested value.
+struct FieldEntry {
+    name: String,
+    variant: Ident,
+    groups: Vec<String>,
+    nested: Option<Type>,
+}
 
-    let fields = fields
+/// Collects the [`FieldEntry`] values for a named-field struct or struct-like enum variant,
+/// applying `#[serde(rename)]`/`#[serde(rename_all = "...")]` and skipping
+/// `#[serde(skip)]`/`#[serde(skip_serializing)]` fields.
+fn named_field_entries(fields: &FieldsNamed, rename_all: Option<Case>) -> Vec<FieldEntry> {
+    fields
         .named
         .iter()
-        .filter_map(|f| f.ident.as_ref())
-        .map(|name| {
-            (
-                name.to_string(),
-                Ident::new(&name.to_string().to_case(Case::Pascal), name.span()),
-            )
+        .filter_map(|f| f.ident.as_ref().map(|ident| (ident, &f.attrs, &f.ty)))
+        .filter_map(|(ident, attrs, ty)| {
+            let attrs = FieldAttrs::parse(attrs);
+            if attrs.skip {
+                return None;
+            }
+
+            let name = attrs.rename.unwrap_or_else(|| match rename_all {
+                Some(case) => ident.to_string().to_case(case),
+                None => ident.to_string(),
+            });
+
+            Some(FieldEntry {
+                name,
+                variant: Ident::new(&ident.to_string().to_case(Case::Pascal), ident.span()),
+                groups: attrs.groups,
+                nested: attrs.nested.then(|| nested_element_type(ty)),
+            })
         })
+        .collect()
+}
+
+/// Collects the [`FieldEntry`] values for a tuple struct, indexing each position (`Field0`,
+/// `Field1`, ...) and using the numeric index as the serialized name, matching what serde
+/// emits for sequence elements. Tuple fields have no identifier to rename, so
+/// `#[serde(rename)]`/`rename_all` don't apply; `#[view(...)]` groups/nesting still do.
+fn tuple_field_entries(fields: &syn::FieldsUnnamed) -> Vec<FieldEntry> {
+    fields
+        .unnamed
+        .iter()
+        .enumerate()
+        .filter_map(|(index, f)| {
+            let attrs = FieldAttrs::parse(&f.attrs);
+            if attrs.skip {
+                return None;
+            }
+
+            Some(FieldEntry {
+                name: index.to_string(),
+                variant: format_ident!("Field{index}"),
+                groups: attrs.groups,
+                nested: attrs.nested.then(|| nested_element_type(&f.ty)),
+            })
+        })
+        .collect()
+}
+
+/// For enums, the union of struct-variant fields may contain the same field more than once
+/// (e.g. two variants both having a `name` field); the first occurrence wins.
+///
+/// This dedups by the generated variant identifier rather than the serialized name: two fields
+/// with different serialized names can still case-convert to the same Pascal-case identifier
+/// (e.g. `foo_bar` and a renamed `"fooBar"` both become `FooBar`), which would otherwise produce
+/// a `Fields` enum with a duplicate variant and fail to compile.
+fn dedup_field_entries(entries: Vec<FieldEntry>) -> Vec<FieldEntry> {
+    let mut seen = std::collections::HashSet::new();
+    entries
+        .into_iter()
+        .filter(|entry| seen.insert(entry.variant.to_string()))
+        .collect()
+}
+
+/// Unwraps a single layer of `Vec<T>`/`Option<T>`/`HashMap<K, T>`/`BTreeMap<K, T>` to find the
+/// concrete element type that a `#[view(nested)]` field's own `View` impl lives on. Any other
+/// type is assumed to directly implement `serde_view::View` itself.
+fn nested_element_type(ty: &Type) -> Type {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            let wrapper = matches!(
+                segment.ident.to_string().as_str(),
+                "Vec" | "Option" | "HashMap" | "BTreeMap"
+            );
+            if wrapper {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(GenericArgument::Type(inner)) = args.args.last() {
+                        return inner.clone();
+                    }
+                }
+            }
+        }
+    }
+    ty.clone()
+}
+
+fn view_fields(name: &Ident, fields: Vec<FieldEntry>) -> TokenStream {
+    let groups = {
+        let mut groups: Vec<(String, Vec<&Ident>)> = Vec::new();
+        for entry in &fields {
+            for group in &entry.groups {
+                match groups.iter_mut().find(|(name, _)| name == group) {
+                    Some((_, variants)) => variants.push(&entry.variant),
+                    None => groups.push((group.clone(), vec![&entry.variant])),
+                }
+            }
+        }
+        groups
+    };
+
+    let group_impl = (!groups.is_empty()).then(|| {
+        let group_arms = groups.iter().map(|(name, variants)| {
+            quote! {
+                #name => [#(Self::#variants, )*].into_iter().collect()
+            }
+        });
+
+        quote! {
+            fn group(name: &str) -> serde_view::Result<std::collections::HashSet<Self>> {
+                Ok(match name {
+                    #(#group_arms, )*
+                    s => return Err(serde_view::Error::UnknownField(s.to_string())),
+                })
+            }
+        }
+    });
+
+    let nested_entries = fields
+        .iter()
+        .filter(|entry| entry.nested.is_some())
         .collect::<Vec<_>>();
 
-    let variants = fields.iter().map(|(_, variant)| {
+    let is_nested_impl = (!nested_entries.is_empty()).then(|| {
+        let nested_variants = nested_entries.iter().map(|entry| &entry.variant);
+        quote! {
+            fn is_nested(&self) -> bool {
+                matches!(self, #(Self::#nested_variants)|*)
+            }
+        }
+    });
+
+    let parse_nested_impl = (!nested_entries.is_empty()).then(|| {
+        let arms = nested_entries.iter().map(|entry| {
+            let variant = &entry.variant;
+            let ty = entry.nested.as_ref().expect("filtered to nested entries");
+            quote! {
+                Self::#variant => serde_view::NestedSelection::validate::<<#ty as serde_view::View>::Fields>(path)
+            }
+        });
+
+        let has_leaf_fields = nested_entries.len() < fields.len();
+        let catch_all = has_leaf_fields.then(|| {
+            quote! {
+                _ => Err(serde_view::Error::UnknownField(path.to_string())),
+            }
+        });
+
+        quote! {
+            fn parse_nested(&self, path: &str) -> serde_view::Result<serde_view::NestedSelection> {
+                match self {
+                    #(#arms, )*
+                    #catch_all
+                }
+            }
+        }
+    });
+
+    let variants = fields.iter().map(|entry| {
+        let variant = &entry.variant;
         quote! {
             #variant
         }
     });
-    let as_str_impl = fields.iter().map(|(name, variant)| {
+    let as_str_impl = fields.iter().map(|entry| {
+        let (name, variant) = (&entry.name, &entry.variant);
         quote! {
             Self::#variant => #name
         }
     });
-    let from_str_impl_1 = fields.iter().map(|(name, variant)| {
+    // A `Fields` enum without variants (unit structs, unit-only enums) still needs an arm in
+    // `match self`, as a reference to an empty enum is not treated as uninhabited.
+    let as_str_impl =
+        as_str_impl.chain(fields.is_empty().then(|| quote! { _ => unreachable!() }));
+    let from_str_impl_1 = fields.iter().map(|entry| {
+        let (name, variant) = (&entry.name, &entry.variant);
         quote! {
             #name => Self::#variant
         }
     });
-    let from_str_impl_2 = fields.iter().map(|(name, variant)| {
+    let from_str_impl_2 = fields.iter().map(|entry| {
+        let (name, variant) = (&entry.name, &entry.variant);
         quote! {
             #name => Self::#variant
         }
@@ -106,6 +286,11 @@ fn view_fields(name: &Ident, data: &DataStruct) -> TokenStream {
                 })
             }
 
+            #group_impl
+
+            #is_nested_impl
+
+            #parse_nested_impl
         }
 
         impl std::str::FromStr for #name {
@@ -120,3 +305,92 @@ fn view_fields(name: &Ident, data: &DataStruct) -> TokenStream {
         }
     }
 }
+
+/// The parsed, effective `#[serde(...)]` and `#[view(...)]` attributes of a single field.
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    skip: bool,
+    groups: Vec<String>,
+    nested: bool,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> Self {
+        let mut result = Self::default();
+
+        for attr in attrs {
+            if attr.path().is_ident("serde") {
+                let _ = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        let value = meta.value()?;
+                        let s: syn::LitStr = value.parse()?;
+                        result.rename = Some(s.value());
+                    } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                        result.skip = true;
+                    }
+
+                    Ok(())
+                });
+            } else if attr.path().is_ident("view") {
+                let _ = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("group") {
+                        let value = meta.value()?;
+                        let s: syn::LitStr = value.parse()?;
+                        result.groups.push(s.value());
+                    } else if meta.path.is_ident("groups") {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        let names = content.parse_terminated(
+                            <syn::LitStr as syn::parse::Parse>::parse,
+                            syn::Token![,],
+                        )?;
+                        result
+                            .groups
+                            .extend(names.into_iter().map(|name| name.value()));
+                    } else if meta.path.is_ident("nested") {
+                        result.nested = true;
+                    }
+
+                    Ok(())
+                });
+            }
+        }
+
+        result
+    }
+}
+
+/// Parses the container-level `#[serde(rename_all = "...")]` attribute, mapping the
+/// serde-defined casing name to the [`Case`] used to rename each field.
+fn rename_all_case(attrs: &[Attribute]) -> Option<Case> {
+    let mut case = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                let value = meta.value()?;
+                let s: syn::LitStr = value.parse()?;
+                case = match s.value().as_str() {
+                    "lowercase" => Some(Case::Flat),
+                    "UPPERCASE" => Some(Case::UpperFlat),
+                    "PascalCase" => Some(Case::Pascal),
+                    "camelCase" => Some(Case::Camel),
+                    "snake_case" => Some(Case::Snake),
+                    "SCREAMING_SNAKE_CASE" => Some(Case::UpperSnake),
+                    "kebab-case" => Some(Case::Kebab),
+                    "SCREAMING-KEBAB-CASE" => Some(Case::UpperKebab),
+                    _ => None,
+                };
+            }
+
+            Ok(())
+        });
+    }
+
+    case
+}
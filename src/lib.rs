@@ -54,10 +54,13 @@
 
 mod ser;
 
-pub use ser::*;
 pub use serde_view_macros::View;
 
-use std::{collections::HashSet, fmt, hash::Hash};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    hash::Hash,
+};
 
 pub trait ViewFields: Clone + Copy + Hash + PartialEq + Eq {
     fn as_str(&self) -> &'static str;
@@ -70,6 +73,34 @@ pub trait ViewFields: Clone + Copy + Hash + PartialEq + Eq {
     fn from_str_split(names: &str) -> Result<HashSet<Self>> {
         Self::from_str_iter(names.split(','))
     }
+
+    /// Resolve a named `#[view(group = "...")]` group to the set of fields it contains.
+    ///
+    /// The default implementation has no known groups and always fails; the [`View`] derive
+    /// overrides this when the struct declares at least one group.
+    fn group(name: &str) -> Result<HashSet<Self>> {
+        Err(Error::UnknownField(name.to_string()))
+    }
+
+    /// Whether this field's type also implements [`View`] (directly, or through a
+    /// `Vec`/`Option`/`HashMap`), making it eligible for a dotted, nested [`Selection`].
+    ///
+    /// The default implementation treats every field as a leaf; the [`View`] derive overrides
+    /// this for fields marked `#[view(nested)]`.
+    fn is_nested(&self) -> bool {
+        false
+    }
+
+    /// Parse the remainder of a dotted path (everything after this field's own segment)
+    /// against the nested type's own fields, recursively.
+    ///
+    /// The default implementation treats every field as a leaf, for which no further path
+    /// segments are valid; the [`View`] derive overrides this for fields marked
+    /// `#[view(nested)]`, dispatching to the nested type's own `ViewFields::from_str` (and,
+    /// in turn, its own `parse_nested`) at every depth.
+    fn parse_nested(&self, path: &str) -> Result<NestedSelection> {
+        Err(Error::UnknownField(path.to_string()))
+    }
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -102,12 +133,132 @@ impl<VF: ViewFields> IntoField<VF> for &str {
     }
 }
 
+/// Whether a [`Selection`] is an allowlist or a blocklist.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SelectionMode {
+    /// Serialize only the selected fields. An empty selection means "all fields".
+    Allow,
+    /// Serialize every field that is *not* selected. An empty selection also means
+    /// "all fields", since nothing is excluded.
+    Deny,
+}
+
+/// A sub-selection of the fields of a nested value (a field whose own type also
+/// implements [`View`]), keyed by the serialized name of the nested type's fields.
+///
+/// An empty selection means "all of it". This mirrors [`Selection`], but is keyed by plain
+/// strings rather than a concrete [`ViewFields`] type, since the nested type's `Fields` enum
+/// isn't known at the point where the selection tree is built.
+#[derive(Clone, Debug, Default)]
+pub struct NestedSelection {
+    children: HashMap<String, NestedSelection>,
+}
+
+impl NestedSelection {
+    /// Parse a single dotted path against a nested type's own [`ViewFields`], validating every
+    /// segment recursively rather than only the first.
+    pub fn validate<VF: ViewFields>(path: &str) -> Result<Self> {
+        let mut selection = Self::default();
+        selection.insert_validated::<VF>(path)?;
+        Ok(selection)
+    }
+
+    fn insert_validated<VF: ViewFields>(&mut self, path: &str) -> Result<()> {
+        let (head, rest) = match path.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (path, None),
+        };
+
+        let field = VF::from_str(head)?;
+        let child = self.children.entry(field.as_str().to_string()).or_default();
+        if let Some(rest) = rest {
+            child.merge(field.parse_nested(rest)?);
+        }
+
+        Ok(())
+    }
+
+    /// Union another selection's children into this one, merging recursively rather than
+    /// overwriting, since multiple dotted paths may target the same parent field.
+    fn merge(&mut self, other: NestedSelection) {
+        for (name, child) in other.children {
+            self.children.entry(name).or_default().merge(child);
+        }
+    }
+}
+
+/// A selection tree of the fields to serialize.
+///
+/// Each selected field optionally carries its own [`NestedSelection`], used when that field's
+/// type also implements [`View`] (e.g. a sub-struct, or a `Vec`/`Option`/`HashMap` of one). A
+/// field selected without a nested selection means "all of it".
+#[derive(Clone, Debug)]
+pub struct Selection<VF: ViewFields> {
+    entries: HashMap<VF, NestedSelection>,
+}
+
+impl<VF: ViewFields> Default for Selection<VF> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<VF: ViewFields> Selection<VF> {
+    /// Parse a single, possibly dotted, field path (e.g. `"author.name"`).
+    pub fn from_path(path: &str) -> Result<Self> {
+        let mut selection = Self::default();
+        selection.insert(path)?;
+        Ok(selection)
+    }
+
+    /// Parse a list of possibly dotted field paths, merging them into one selection tree.
+    ///
+    /// A bare field name selects the whole subtree. If both a bare name and a dotted path
+    /// are given for the same field (in either order), the dotted path wins and restricts the
+    /// selection to the given sub-paths.
+    pub fn from_str_iter<'a>(paths: impl IntoIterator<Item = &'a str>) -> Result<Self> {
+        let mut selection = Self::default();
+        for path in paths {
+            selection.insert(path)?;
+        }
+        Ok(selection)
+    }
+
+    /// Parse a comma-separated list of possibly dotted field paths, e.g.
+    /// `"id,author.name,tags.slug"`.
+    pub fn from_str_split(paths: &str) -> Result<Self> {
+        Self::from_str_iter(paths.split(','))
+    }
+
+    fn insert(&mut self, path: &str) -> Result<()> {
+        let (head, rest) = match path.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (path, None),
+        };
+
+        let field = VF::from_str(head)?;
+        let entry = self.entries.entry(field).or_default();
+        if let Some(rest) = rest {
+            entry.merge(field.parse_nested(rest)?);
+        }
+
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
 pub struct ViewContext<'v, T>
 where
     T: View,
 {
     inner: &'v T,
-    fields: HashSet<T::Fields>,
+    mode: SelectionMode,
+    selection: Selection<T::Fields>,
 }
 
 impl<'v, T> ViewContext<'v, T>
@@ -119,31 +270,91 @@ where
         I: IntoIterator<Item = IF>,
         IF: IntoField<T::Fields>,
     {
-        self.fields = fields
-            .into_iter()
-            .map(|f| f.into_field())
-            .collect::<Result<_>>()?;
+        self.mode = SelectionMode::Allow;
+        self.selection = Self::flat_selection(fields)?;
+        Ok(self)
+    }
+
+    /// Serialize every field *except* the given ones.
+    ///
+    /// This switches the context into deny mode: [`Self::add_field`]/[`Self::add_fields`]
+    /// keep adding to the set of excluded fields until an allowlist is selected again via
+    /// [`Self::with_fields`]. Mixing the two styles always uses whichever mode was selected
+    /// last; there is no way to combine an allow- and a deny-selection at the same time.
+    pub fn with_fields_except<I, IF>(mut self, fields: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = IF>,
+        IF: IntoField<T::Fields>,
+    {
+        self.mode = SelectionMode::Deny;
+        self.selection = Self::flat_selection(fields)?;
         Ok(self)
     }
 
+    /// Alias for [`Self::with_fields_except`].
+    pub fn without_fields<I, IF>(self, fields: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = IF>,
+        IF: IntoField<T::Fields>,
+    {
+        self.with_fields_except(fields)
+    }
+
+    /// Select fields using a pre-built, possibly nested [`Selection`], e.g. one parsed via
+    /// [`Selection::from_str_split`] from a dotted, GraphQL-style field list.
+    pub fn with_selection(mut self, selection: Selection<T::Fields>) -> Self {
+        self.mode = SelectionMode::Allow;
+        self.selection = selection;
+        self
+    }
+
     pub fn add_fields<I, IF>(mut self, fields: I) -> Result<Self>
     where
         I: IntoIterator<Item = IF>,
         IF: IntoField<T::Fields>,
     {
-        self.fields.extend(
-            fields
-                .into_iter()
-                .map(|f| f.into_field())
-                .collect::<Result<HashSet<T::Fields>>>()?,
-        );
+        for field in fields {
+            self.selection
+                .entries
+                .entry(field.into_field()?)
+                .or_default();
+        }
         Ok(self)
     }
 
     pub fn add_field(mut self, field: impl IntoField<T::Fields>) -> Result<Self> {
-        self.fields.insert(field.into_field()?);
+        self.selection
+            .entries
+            .entry(field.into_field()?)
+            .or_default();
         Ok(self)
     }
+
+    /// Select all fields belonging to a named `#[view(group = "...")]` group.
+    pub fn with_group(self, name: &str) -> Result<Self> {
+        self.with_fields(T::Fields::group(name)?)
+    }
+
+    /// Select the union of all fields belonging to the named groups.
+    pub fn with_groups<'n>(self, names: impl IntoIterator<Item = &'n str>) -> Result<Self> {
+        let mut fields = HashSet::new();
+        for name in names {
+            fields.extend(T::Fields::group(name)?);
+        }
+        self.with_fields(fields)
+    }
+
+    fn flat_selection<I, IF>(fields: I) -> Result<Selection<T::Fields>>
+    where
+        I: IntoIterator<Item = IF>,
+        IF: IntoField<T::Fields>,
+    {
+        let mut selection = Selection::default();
+        for field in fields {
+            selection.entries.entry(field.into_field()?).or_default();
+        }
+        Ok(selection)
+    }
 }
 
 pub trait View: Sized + serde::Serialize {
@@ -152,7 +363,8 @@ pub trait View: Sized + serde::Serialize {
     fn as_view(&self) -> ViewContext<Self> {
         ViewContext {
             inner: self,
-            fields: Default::default(),
+            mode: SelectionMode::Allow,
+            selection: Default::default(),
         }
     }
 }
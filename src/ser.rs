@@ -0,0 +1,484 @@
+//! The [`serde::Serialize`] implementation for [`crate::ViewContext`].
+//!
+//! This works by wrapping the actual [`serde::Serializer`], intercepting struct
+//! serialization so that only the selected fields are forwarded to it. Fields carrying a
+//! nested selection are re-wrapped the same way, recursively, so that `View`-typed fields (and
+//! `Vec`/`Option`/`HashMap` of them) are filtered as well.
+
+use crate::{NestedSelection, Selection, SelectionMode, View, ViewContext, ViewFields};
+use serde::{
+    ser::{
+        SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTupleStruct,
+        SerializeTupleVariant, Serializer,
+    },
+    Serialize,
+};
+
+impl<'v, T> Serialize for ViewContext<'v, T>
+where
+    T: View,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let filter = RootFilter {
+            mode: self.mode,
+            selection: &self.selection,
+        };
+        self.inner.serialize(ViewSerializer {
+            serializer,
+            filter: &filter,
+        })
+    }
+}
+
+/// Decides, for a single level of nesting, whether a serialized field key is selected, and
+/// what (if any) selection applies to values nested underneath it.
+trait FieldFilter {
+    fn is_selected(&self, key: &str) -> bool;
+    fn nested(&self, key: &str) -> Option<&dyn FieldFilter>;
+}
+
+/// The top-level [`FieldFilter`], backed by a [`Selection`] and its [`SelectionMode`].
+struct RootFilter<'f, VF: ViewFields> {
+    mode: SelectionMode,
+    selection: &'f Selection<VF>,
+}
+
+impl<'f, VF: ViewFields> FieldFilter for RootFilter<'f, VF> {
+    fn is_selected(&self, key: &str) -> bool {
+        let selected = VF::from_str(key)
+            .map(|field| self.selection.entries.contains_key(&field))
+            .unwrap_or(false);
+
+        match self.mode {
+            SelectionMode::Allow => self.selection.is_empty() || selected,
+            SelectionMode::Deny => !selected,
+        }
+    }
+
+    fn nested(&self, key: &str) -> Option<&dyn FieldFilter> {
+        let field = VF::from_str(key).ok()?;
+        let nested = self.selection.entries.get(&field)?;
+        (!nested.children.is_empty()).then_some(nested as &dyn FieldFilter)
+    }
+}
+
+impl FieldFilter for NestedSelection {
+    fn is_selected(&self, key: &str) -> bool {
+        self.children.is_empty() || self.children.contains_key(key)
+    }
+
+    fn nested(&self, key: &str) -> Option<&dyn FieldFilter> {
+        let nested = self.children.get(key)?;
+        (!nested.children.is_empty()).then_some(nested as &dyn FieldFilter)
+    }
+}
+
+/// Wraps a single value together with the [`FieldFilter`] that applies to it, so that
+/// serializing it recurses back through [`ViewSerializer`] instead of escaping to the
+/// underlying, unfiltered serializer.
+struct Nested<'a, 'f, T: ?Sized> {
+    value: &'a T,
+    filter: &'f dyn FieldFilter,
+}
+
+impl<'a, 'f, T> Serialize for Nested<'a, 'f, T>
+where
+    T: Serialize + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(ViewSerializer {
+            serializer,
+            filter: self.filter,
+        })
+    }
+}
+
+macro_rules! forward {
+    ($name:ident, $ty:ty) => {
+        fn $name(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+            self.serializer.$name(v)
+        }
+    };
+}
+
+/// Wraps a [`Serializer`], filtering struct fields (and, recursively, nested `View` values)
+/// by a [`FieldFilter`].
+struct ViewSerializer<'f, S> {
+    serializer: S,
+    filter: &'f dyn FieldFilter,
+}
+
+impl<'f, S> Serializer for ViewSerializer<'f, S>
+where
+    S: Serializer,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    type SerializeSeq = ViewSeqSerializer<'f, S::SerializeSeq>;
+    type SerializeTuple = S::SerializeTuple;
+    type SerializeTupleStruct = ViewTupleSerializer<'f, S::SerializeTupleStruct>;
+    type SerializeTupleVariant = ViewTupleSerializer<'f, S::SerializeTupleVariant>;
+    type SerializeMap = ViewMapSerializer<'f, S::SerializeMap>;
+    type SerializeStruct = ViewStructSerializer<'f, S::SerializeStruct>;
+    type SerializeStructVariant = ViewStructSerializer<'f, S::SerializeStructVariant>;
+
+    forward!(serialize_bool, bool);
+    forward!(serialize_i8, i8);
+    forward!(serialize_i16, i16);
+    forward!(serialize_i32, i32);
+    forward!(serialize_i64, i64);
+    forward!(serialize_u8, u8);
+    forward!(serialize_u16, u16);
+    forward!(serialize_u32, u32);
+    forward!(serialize_u64, u64);
+    forward!(serialize_f32, f32);
+    forward!(serialize_f64, f64);
+    forward!(serialize_char, char);
+    forward!(serialize_str, &str);
+    forward!(serialize_bytes, &[u8]);
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.serializer.serialize_none()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.serializer.serialize_some(&Nested {
+            value,
+            filter: self.filter,
+        })
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.serializer.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serializer.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serializer
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.serializer.serialize_newtype_struct(name, value)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.serializer
+            .serialize_newtype_variant(name, variant_index, variant, value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(ViewSeqSerializer {
+            inner: self.serializer.serialize_seq(len)?,
+            filter: self.filter,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serializer.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(ViewTupleSerializer {
+            inner: self.serializer.serialize_tuple_struct(name, len)?,
+            filter: self.filter,
+            index: 0,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(ViewTupleSerializer {
+            inner: self
+                .serializer
+                .serialize_tuple_variant(name, variant_index, variant, len)?,
+            filter: self.filter,
+            index: 0,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(ViewMapSerializer {
+            inner: self.serializer.serialize_map(len)?,
+            filter: self.filter,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(ViewStructSerializer {
+            inner: self.serializer.serialize_struct(name, len)?,
+            filter: self.filter,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(ViewStructSerializer {
+            inner: self
+                .serializer
+                .serialize_struct_variant(name, variant_index, variant, len)?,
+            filter: self.filter,
+        })
+    }
+}
+
+/// Wraps a [`SerializeStruct`], skipping fields that are not part of the selection, and
+/// re-wrapping fields that carry a nested selection.
+struct ViewStructSerializer<'f, S> {
+    inner: S,
+    filter: &'f dyn FieldFilter,
+}
+
+impl<'f, S> SerializeStruct for ViewStructSerializer<'f, S>
+where
+    S: SerializeStruct,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        if !self.filter.is_selected(key) {
+            return self.inner.skip_field(key);
+        }
+
+        match self.filter.nested(key) {
+            Some(nested) => self.inner.serialize_field(
+                key,
+                &Nested {
+                    value,
+                    filter: nested,
+                },
+            ),
+            None => self.inner.serialize_field(key, value),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<'f, S> SerializeStructVariant for ViewStructSerializer<'f, S>
+where
+    S: SerializeStructVariant,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        if !self.filter.is_selected(key) {
+            return self.inner.skip_field(key);
+        }
+
+        match self.filter.nested(key) {
+            Some(nested) => self.inner.serialize_field(
+                key,
+                &Nested {
+                    value,
+                    filter: nested,
+                },
+            ),
+            None => self.inner.serialize_field(key, value),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+/// Wraps a [`SerializeTupleStruct`]/[`SerializeTupleVariant`], filtering positional fields by
+/// their numeric index (`"0"`, `"1"`, ...), matching the indices the [`View`] derive assigns to
+/// tuple struct fields. Neither underlying trait supports skipping an element (doing so would
+/// shift every later position), so an unselected field is serialized as `()` in its place.
+struct ViewTupleSerializer<'f, S> {
+    inner: S,
+    filter: &'f dyn FieldFilter,
+    index: usize,
+}
+
+impl<'f, S> SerializeTupleStruct for ViewTupleSerializer<'f, S>
+where
+    S: SerializeTupleStruct,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = self.index.to_string();
+        self.index += 1;
+
+        if !self.filter.is_selected(&key) {
+            return self.inner.serialize_field(&());
+        }
+
+        match self.filter.nested(&key) {
+            Some(nested) => self.inner.serialize_field(&Nested {
+                value,
+                filter: nested,
+            }),
+            None => self.inner.serialize_field(value),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<'f, S> SerializeTupleVariant for ViewTupleSerializer<'f, S>
+where
+    S: SerializeTupleVariant,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = self.index.to_string();
+        self.index += 1;
+
+        if !self.filter.is_selected(&key) {
+            return self.inner.serialize_field(&());
+        }
+
+        match self.filter.nested(&key) {
+            Some(nested) => self.inner.serialize_field(&Nested {
+                value,
+                filter: nested,
+            }),
+            None => self.inner.serialize_field(value),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+/// Wraps a [`SerializeSeq`], applying the same [`FieldFilter`] to every element.
+struct ViewSeqSerializer<'f, S> {
+    inner: S,
+    filter: &'f dyn FieldFilter,
+}
+
+impl<'f, S> SerializeSeq for ViewSeqSerializer<'f, S>
+where
+    S: SerializeSeq,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.serialize_element(&Nested {
+            value,
+            filter: self.filter,
+        })
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+/// Wraps a [`SerializeMap`], applying the same [`FieldFilter`] to every value (keys are
+/// passed through unchanged).
+struct ViewMapSerializer<'f, S> {
+    inner: S,
+    filter: &'f dyn FieldFilter,
+}
+
+impl<'f, S> SerializeMap for ViewMapSerializer<'f, S>
+where
+    S: SerializeMap,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.serialize_key(key)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.serialize_value(&Nested {
+            value,
+            filter: self.filter,
+        })
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
@@ -52,12 +52,12 @@ impl ViewFields for MyRecordFields {
         }
     }
 
-    fn from_str(name: &str) -> Option<Self> {
-        Some(match name {
+    fn from_str(name: &str) -> serde_view::Result<Self> {
+        Ok(match name {
             "some_string" => Self::SomeString,
             "flag" => Self::Flag,
             "optional_flag" => Self::OptionalFlag,
-            _ => return None,
+            _ => return Err(serde_view::Error::UnknownField(name.to_string())),
         })
     }
 }
@@ -79,6 +79,7 @@ fn test_manual() {
             MyRecord::default()
                 .as_view()
                 .with_fields([<MyRecord as View>::Fields::SomeString])
+                .unwrap()
         )
         .unwrap(),
         json!({
@@ -94,6 +95,7 @@ fn test_derived() {
             MyRecordDerived::default()
                 .as_view()
                 .with_fields([<MyRecordDerived as View>::Fields::SomeString])
+                .unwrap()
         )
         .unwrap(),
         json!({
@@ -106,6 +108,7 @@ fn test_derived() {
             MyRecordDerived::default()
                 .as_view()
                 .with_fields([MyRecordDerivedFields::SomeString])
+                .unwrap()
         )
         .unwrap(),
         json!({
@@ -114,9 +117,15 @@ fn test_derived() {
     );
 
     assert_eq!(
-        serde_json::to_value(MyRecordDerived::default().as_view().with_fields(
-            <MyRecordDerived as View>::Fields::from_str_iter("some_string,flag".split(","))
-        ))
+        serde_json::to_value(
+            MyRecordDerived::default()
+                .as_view()
+                .with_fields(
+                    <MyRecordDerived as View>::Fields::from_str_iter("some_string,flag".split(','))
+                        .unwrap()
+                )
+                .unwrap()
+        )
         .unwrap(),
         json!({
             "flag": true,
@@ -125,6 +134,99 @@ fn test_derived() {
     );
 }
 
+#[derive(Clone, Debug, serde_view::View, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedRecord {
+    some_value: String,
+    #[serde(rename = "customName")]
+    other_value: String,
+    #[serde(skip)]
+    internal: String,
+}
+
+impl Default for RenamedRecord {
+    fn default() -> Self {
+        Self {
+            some_value: "a".to_string(),
+            other_value: "b".to_string(),
+            internal: "c".to_string(),
+        }
+    }
+}
+
+#[test]
+fn test_rename() {
+    assert_eq!(
+        serde_json::to_value(
+            RenamedRecord::default()
+                .as_view()
+                .with_fields([
+                    RenamedRecordFields::SomeValue,
+                    RenamedRecordFields::OtherValue
+                ])
+                .unwrap()
+        )
+        .unwrap(),
+        json!({
+            "someValue": "a",
+            "customName": "b",
+        })
+    );
+}
+
+#[derive(Clone, Debug, serde_view::View, serde::Serialize, serde::Deserialize)]
+pub struct GroupedRecord {
+    #[view(group = "summary")]
+    id: String,
+    #[view(group = "summary")]
+    name: String,
+    #[view(groups("summary", "detail"))]
+    description: String,
+    detail_only: String,
+}
+
+impl Default for GroupedRecord {
+    fn default() -> Self {
+        Self {
+            id: "1".to_string(),
+            name: "Widget".to_string(),
+            description: "A widget".to_string(),
+            detail_only: "secret".to_string(),
+        }
+    }
+}
+
+#[test]
+fn test_groups() {
+    assert_eq!(
+        serde_json::to_value(
+            GroupedRecord::default()
+                .as_view()
+                .with_group("summary")
+                .unwrap()
+        )
+        .unwrap(),
+        json!({
+            "id": "1",
+            "name": "Widget",
+            "description": "A widget",
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(
+            GroupedRecord::default()
+                .as_view()
+                .with_groups(["detail"])
+                .unwrap()
+        )
+        .unwrap(),
+        json!({
+            "description": "A widget",
+        })
+    );
+}
+
 #[test]
 fn test_all() {
     // if no fields are selected, this means: all
@@ -140,3 +242,161 @@ fn test_all() {
         })
     );
 }
+
+#[test]
+fn test_exclusion() {
+    assert_eq!(
+        serde_json::to_value(
+            MyRecordDerived::default()
+                .as_view()
+                .with_fields_except([MyRecordDerivedFields::OptionalFlag])
+                .unwrap()
+        )
+        .unwrap(),
+        json!({
+            "flag": true,
+            "some_string": "Hello World",
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(
+            MyRecordDerived::default()
+                .as_view()
+                .without_fields([MyRecordDerivedFields::Flag])
+                .unwrap()
+        )
+        .unwrap(),
+        json!({
+            "some_string": "Hello World",
+            "optional_flag": null,
+        })
+    );
+}
+
+#[derive(Clone, Debug, serde_view::View, serde::Serialize, serde::Deserialize)]
+pub struct Address {
+    street: String,
+    city: String,
+}
+
+impl Default for Address {
+    fn default() -> Self {
+        Self {
+            street: "Main St".to_string(),
+            city: "Springfield".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde_view::View, serde::Serialize, serde::Deserialize)]
+pub struct Person {
+    name: String,
+    #[view(nested)]
+    address: Address,
+}
+
+impl Default for Person {
+    fn default() -> Self {
+        Self {
+            name: "Alice".to_string(),
+            address: Address::default(),
+        }
+    }
+}
+
+#[test]
+fn test_nested_selection() {
+    let selection = serde_view::Selection::from_str_split("name,address.city").unwrap();
+
+    assert_eq!(
+        serde_json::to_value(Person::default().as_view().with_selection(selection)).unwrap(),
+        json!({
+            "name": "Alice",
+            "address": {
+                "city": "Springfield",
+            }
+        })
+    );
+
+    // a bare parent name, without a dotted sub-path, selects all of it
+    let selection = serde_view::Selection::from_str_split("address").unwrap();
+
+    assert_eq!(
+        serde_json::to_value(Person::default().as_view().with_selection(selection)).unwrap(),
+        json!({
+            "address": {
+                "street": "Main St",
+                "city": "Springfield",
+            }
+        })
+    );
+}
+
+#[test]
+fn test_nested_selection_validates_sub_path() {
+    // the sub-path must name a real field of the nested type's own `Fields`
+    assert!(serde_view::Selection::<PersonFields>::from_path("address.bogus").is_err());
+
+    // and a trailing segment past a leaf field is rejected too, not silently accepted
+    assert!(serde_view::Selection::<PersonFields>::from_path("address.city.foo").is_err());
+}
+
+#[derive(Clone, Debug, serde_view::View, serde::Serialize, serde::Deserialize)]
+pub struct Point(i32, i32);
+
+#[test]
+fn test_tuple_struct() {
+    assert_eq!(
+        serde_json::to_value(
+            Point(1, 2)
+                .as_view()
+                .with_fields([PointFields::Field0])
+                .unwrap()
+        )
+        .unwrap(),
+        json!([1, null])
+    );
+}
+
+#[derive(Clone, Debug, serde_view::View, serde::Serialize, serde::Deserialize)]
+pub enum Shape {
+    Circle { radius: f64, color: String },
+    Square { side: f64, color: String },
+}
+
+#[test]
+fn test_enum_struct_variant() {
+    assert_eq!(
+        serde_json::to_value(
+            Shape::Circle {
+                radius: 2.0,
+                color: "red".to_string(),
+            }
+            .as_view()
+            .with_fields([ShapeFields::Radius])
+            .unwrap()
+        )
+        .unwrap(),
+        json!({
+            "Circle": {
+                "radius": 2.0,
+            }
+        })
+    );
+}
+
+#[derive(Clone, Debug, serde_view::View, serde::Serialize, serde::Deserialize)]
+pub enum Status {
+    Active,
+    Inactive,
+}
+
+#[test]
+fn test_unit_enum() {
+    // unit variants carry no fields to select; the view just serializes them as-is
+    assert_eq!(
+        serde_json::to_value(Status::Active.as_view()).unwrap(),
+        json!("Active")
+    );
+}